@@ -1,84 +1,324 @@
+use std::collections::HashSet;
+
 use aes::Aes256;
 use fpe::ff1::{FlexibleNumeralString, FF1};
+use rand::{Rng, RngCore};
+
+/// Splits `number` into `digit_count` numerals in the given `radix`, most
+/// significant first. Adds leading zeros if needed. Shared by
+/// [`RandomIdGenerator`] (fixed to radix 10) and [`AlphaIdGenerator`]
+/// (radix is the alphabet length).
+fn split_digits(mut number: u64, digit_count: u16, radix: u64) -> Vec<u16> {
+    let mut digits = Vec::new();
+    while number > 0 {
+        digits.push((number % radix) as u16);
+        number /= radix;
+    }
+    while digits.len() < digit_count as usize {
+        digits.push(0);
+    }
+    digits.reverse();
+    digits
+}
+
+/// Joins numerals in the given `radix`, most significant first, back into a
+/// number. The inverse of [`split_digits`].
+fn join_digits(digits: &[u16], radix: u64) -> u64 {
+    digits
+        .iter()
+        .fold(0u64, |acc, &digit| acc * radix + digit as u64)
+}
+
+/// Total number of distinct ids in `[0, radix^digit_count)`, checked so
+/// that an unreasonably large `digit_count` is caught instead of silently
+/// wrapping.
+fn digit_space_len(digit_count: u16, radix: u64) -> u64 {
+    (radix as u128)
+        .checked_pow(digit_count as u32)
+        .and_then(|len| u64::try_from(len).ok())
+        .expect("digits is too large for the id space to fit in a u64")
+}
+
+/// The `['0'..='9']` alphabet that keeps [`RandomIdGenerator`] a thin,
+/// numeric wrapper over [`AlphaIdGenerator`].
+const DECIMAL_ALPHABET: [char; 10] = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
 
 /// An iterator that returns decimal numbers in a random order.
 /// All returned numbers are in the range of [0, 10^digits).
 ///
-/// Numbers are generated using the FF1 algorithm.
+/// Numbers are generated using the FF1 algorithm. This is
+/// [`AlphaIdGenerator::new`] restricted to the decimal alphabet, with `u64`
+/// ids instead of `String`s.
 ///
 /// ## Usage
 /// ```
 /// use random_id::RandomIdGenerator;
-/// use rand::prelude::*;
 ///
 /// let mut rng = rand::thread_rng();
-/// let mut key = [0u8; 32];
-/// rng.fill(&mut key);
-///
-/// let mut id_generator = RandomIdGenerator::new(key, 0, 1);
+/// let mut id_generator = RandomIdGenerator::from_rng(&mut rng, 0, 1);
 ///
 /// for i in id_generator.take(10) {
 ///    println!("{}", i);
 /// }
 /// ```
 pub struct RandomIdGenerator {
+    inner: AlphaIdGenerator,
+}
+
+impl RandomIdGenerator {
+    pub fn new(key: [u8; 32], tweak: u64, digits: u16) -> Self {
+        Self {
+            inner: AlphaIdGenerator::new(key, tweak, digits, &DECIMAL_ALPHABET),
+        }
+    }
+
+    /// Constructs a generator keyed from `rng`, matching the
+    /// `SeedableRng::from_rng` convention: `rng` is used only to fill the
+    /// AES-256 key, it is not consulted again afterwards.
+    pub fn from_rng<R: RngCore>(rng: &mut R, tweak: u64, digits: u16) -> Self {
+        Self {
+            inner: AlphaIdGenerator::from_rng(rng, tweak, digits, &DECIMAL_ALPHABET),
+        }
+    }
+
+    /// Constructs a generator using `seed` directly as the AES-256 key.
+    /// `seed` IS the secret: anyone who has it can invert any id this
+    /// generator produces back to its sequence index. Use this when you
+    /// need a reproducible shuffle, e.g. in tests.
+    pub fn from_seed(seed: [u8; 32], tweak: u64, digits: u16) -> Self {
+        Self {
+            inner: AlphaIdGenerator::from_seed(seed, tweak, digits, &DECIMAL_ALPHABET),
+        }
+    }
+
+    /// Constructs a generator that emits `String` ids drawn from a custom
+    /// `alphabet` instead of decimal digits, using `alphabet.len()` as the
+    /// FF1 radix.
+    pub fn with_alphabet(
+        key: [u8; 32],
+        tweak: u64,
+        digits: u16,
+        alphabet: &[char],
+    ) -> AlphaIdGenerator {
+        AlphaIdGenerator::new(key, tweak, digits, alphabet)
+    }
+
+    /// Finds the sequence index that produces `id`, by running FF1 in
+    /// reverse. Returns `None` if `id` is outside `[0, len())`, or if it
+    /// decrypts to an index outside `[0, len())`, which can only happen if
+    /// `id` was not produced by this generator's key, tweak and digit count.
+    pub fn index_of(&self, id: u64) -> Option<u64> {
+        if id >= self.inner.len() {
+            return None;
+        }
+
+        let id = format!("{:0width$}", id, width = self.inner.digits as usize);
+        self.inner.index_of(&id)
+    }
+
+    /// Restarts the stream from `index`: the next id returned is the one at
+    /// sequence index `index`. A caller that persisted the number of ids
+    /// already taken (one past the index of the last id returned) can
+    /// continue without replaying or repeating ids.
+    pub fn resume_from(&mut self, index: u64) {
+        self.inner.resume_from(index);
+    }
+
+    /// Draws `k` distinct ids without replacement and without iterating the
+    /// whole `[0, len())` space, in O(k) FF1 calls. Uses Floyd's partial
+    /// Fisher-Yates algorithm to sample `k` distinct indices uniformly,
+    /// then encrypts each one. `k` is capped to `len()` if it's larger.
+    pub fn choose_multiple<R: RngCore>(&self, rng: &mut R, k: usize) -> Vec<u64> {
+        self.inner
+            .choose_multiple(rng, k)
+            .into_iter()
+            .map(|id| id.parse().expect("decimal alphabet always parses as u64"))
+            .collect()
+    }
+}
+
+impl Iterator for RandomIdGenerator {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let id = self.inner.next()?;
+        Some(id.parse().expect("decimal alphabet always parses as u64"))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn count(self) -> usize {
+        self.inner.count()
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        let id = self.inner.last()?;
+        Some(id.parse().expect("decimal alphabet always parses as u64"))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let id = self.inner.nth(n)?;
+        Some(id.parse().expect("decimal alphabet always parses as u64"))
+    }
+}
+
+impl DoubleEndedIterator for RandomIdGenerator {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let id = self.inner.next_back()?;
+        Some(id.parse().expect("decimal alphabet always parses as u64"))
+    }
+}
+
+/// An iterator that returns strings drawn from a custom `alphabet` in a
+/// random order, with the same non-repeating, format-preserving guarantee
+/// as [`RandomIdGenerator`]. All returned strings have exactly `digits`
+/// characters. This is the single FF1 bijection implementation that backs
+/// both generators; construct one directly via [`AlphaIdGenerator::new`] or
+/// [`RandomIdGenerator::with_alphabet`].
+pub struct AlphaIdGenerator {
     key: [u8; 32],
     digits: u16,
-    next: u16,
+    next: u64,
+    back: u64,
     tweak: Vec<u8>,
+    alphabet: Vec<char>,
 }
 
-impl RandomIdGenerator {
-    pub fn new(key: [u8; 32], tweak: u64, digits: u16) -> Self {
+impl AlphaIdGenerator {
+    pub fn new(key: [u8; 32], tweak: u64, digits: u16, alphabet: &[char]) -> Self {
         let tweak = tweak.to_be_bytes().to_vec();
+        let back = digit_space_len(digits, alphabet.len() as u64);
         Self {
             key,
             tweak,
             digits,
             next: 0,
+            back,
+            alphabet: alphabet.to_vec(),
         }
     }
 
-    /// Splits a 4 digits decimal number into its digits. Adds leading zeros if needed.
-    fn split_number_digits(&self, mut number: u16) -> Vec<u16> {
-        let mut digits = Vec::new();
-        while number > 0 {
-            digits.push(number % 10);
-            number /= 10;
-        }
-        while digits.len() < self.digits as usize {
-            digits.push(0);
-        }
-        digits.reverse();
-        digits
+    /// Constructs a generator keyed from `rng`, matching the
+    /// `SeedableRng::from_rng` convention: `rng` is used only to fill the
+    /// AES-256 key, it is not consulted again afterwards.
+    pub fn from_rng<R: RngCore>(rng: &mut R, tweak: u64, digits: u16, alphabet: &[char]) -> Self {
+        let mut key = [0u8; 32];
+        rng.fill_bytes(&mut key);
+        Self::new(key, tweak, digits, alphabet)
     }
 
-    fn join_number_digits(digits: &[u16]) -> u16 {
-        digits.iter().fold(0, |acc, &digit| acc * 10 + digit)
+    /// Constructs a generator using `seed` directly as the AES-256 key.
+    /// `seed` IS the secret: anyone who has it can invert any id this
+    /// generator produces back to its sequence index. Use this when you
+    /// need a reproducible shuffle, e.g. in tests.
+    pub fn from_seed(seed: [u8; 32], tweak: u64, digits: u16, alphabet: &[char]) -> Self {
+        Self::new(seed, tweak, digits, alphabet)
+    }
+
+    fn radix(&self) -> u64 {
+        self.alphabet.len() as u64
     }
 
     fn remaining(&self) -> usize {
-        (self.len() - self.next) as usize
+        (self.back - self.next) as usize
     }
 
-    fn len(&self) -> u16 {
-        10u16.pow(self.digits as u32)
+    /// Total number of distinct ids in `[0, radix^digits)`.
+    fn len(&self) -> u64 {
+        digit_space_len(self.digits, self.radix())
     }
-}
 
-impl Iterator for RandomIdGenerator {
-    type Item = u16;
+    fn encode(&self, digits: &[u16]) -> String {
+        digits
+            .iter()
+            .map(|&digit| self.alphabet[digit as usize])
+            .collect()
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        let input = self.split_number_digits(self.next);
+    /// The inverse of [`Self::encode`]. Returns `None` if `id` contains a
+    /// character that isn't in this generator's alphabet.
+    fn decode(&self, id: &str) -> Option<Vec<u16>> {
+        id.chars()
+            .map(|c| self.alphabet.iter().position(|&a| a == c).map(|p| p as u16))
+            .collect()
+    }
+
+    /// Finds the sequence index that produces `id`, by running FF1 in
+    /// reverse. Returns `None` if `id` isn't `digits` characters drawn from
+    /// this generator's alphabet, or if it decrypts to an index outside
+    /// `[0, len())`, which can only happen if `id` was not produced by this
+    /// generator's key, tweak and digit count.
+    pub fn index_of(&self, id: &str) -> Option<u64> {
+        if id.chars().count() != self.digits as usize {
+            return None;
+        }
+
+        let input = self.decode(id)?;
+        let numeral_string = FlexibleNumeralString::from(input);
+
+        let ff = FF1::<Aes256>::new(&self.key, self.radix() as u32).ok()?;
+        let output = ff.decrypt(&self.tweak, &numeral_string).ok()?;
+        let output = Vec::from(output);
+        let index = join_digits(&output, self.radix());
+
+        (index < self.len()).then_some(index)
+    }
+
+    /// Restarts the stream from `index`: the next id returned is the one at
+    /// sequence index `index`. A caller that persisted the number of ids
+    /// already taken (one past the index of the last id returned) can
+    /// continue without replaying or repeating ids.
+    pub fn resume_from(&mut self, index: u64) {
+        self.next = index;
+    }
+
+    /// Draws `k` distinct ids without replacement and without iterating the
+    /// whole `[0, len())` space, in O(k) FF1 calls. Uses Floyd's partial
+    /// Fisher-Yates algorithm to sample `k` distinct indices uniformly,
+    /// then encrypts each one. `k` is capped to `len()` if it's larger.
+    pub fn choose_multiple<R: RngCore>(&self, rng: &mut R, k: usize) -> Vec<String> {
+        let len = self.len();
+        let k = k.min(len as usize);
+        let mut indices = HashSet::with_capacity(k);
+
+        for j in (len - k as u64)..len {
+            let t = rng.gen_range(0..=j);
+            if !indices.insert(t) {
+                indices.insert(j);
+            }
+        }
+
+        indices
+            .into_iter()
+            .filter_map(|index| self.encrypt_index(index))
+            .collect()
+    }
+
+    fn encrypt_index(&self, index: u64) -> Option<String> {
+        let input = split_digits(index, self.digits, self.radix());
         let numeral_string = FlexibleNumeralString::from(input);
 
-        let ff = FF1::<Aes256>::new(&self.key, 10).ok()?;
+        let ff = FF1::<Aes256>::new(&self.key, self.radix() as u32).ok()?;
         let output = ff.encrypt(&self.tweak, &numeral_string).ok()?;
         let output = Vec::from(output);
 
+        Some(self.encode(&output))
+    }
+}
+
+impl Iterator for AlphaIdGenerator {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.back {
+            return None;
+        }
+
+        let id = self.encrypt_index(self.next)?;
         self.next += 1;
-        Some(Self::join_number_digits(&output))
+        Some(id)
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -88,83 +328,87 @@ impl Iterator for RandomIdGenerator {
     fn count(mut self) -> usize {
         let remaining = self.remaining();
 
-        // Set next to total to make sure that the iterator is exhausted.
-        self.next = self.len();
+        // Set next to back to make sure that the iterator is exhausted.
+        self.next = self.back;
         remaining
     }
 
     fn last(mut self) -> Option<Self::Item> {
-        if self.next >= self.len() {
+        if self.next >= self.back {
             return None;
         }
 
-        // Set next to total - 1 to make sure that the iterator returns the last element.
-        self.next = self.len() - 1;
+        // Set next to back - 1 to make sure that the iterator returns the last element.
+        self.next = self.back - 1;
         self.next()
     }
 
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        if self.next + n as u16 >= self.len() {
+        if self.next + n as u64 >= self.back {
             return None;
         }
 
-        self.next += n as u16;
+        self.next += n as u64;
         self.next()
     }
 }
 
+impl DoubleEndedIterator for AlphaIdGenerator {
+    /// Decrements the `back` cursor and encrypts `back - 1`, so the stream
+    /// can be consumed from either end (or split into disjoint forward and
+    /// backward halves) while still guaranteeing no id is ever repeated:
+    /// forward and backward draws both stop as soon as `next` meets `back`.
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.next >= self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        self.encrypt_index(self.back)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use rand::prelude::*;
+    use rand::Rng;
 
     use super::*;
 
     #[test]
     fn test_split_number_digits() {
-        let mut rng = rand::thread_rng();
-        let mut key = [0u8; 32];
-        rng.fill(&mut key);
-
-        let id_generator = RandomIdGenerator::new(key.clone(), 0, 4);
-        assert_eq!(id_generator.split_number_digits(0), [0, 0, 0, 0]);
-        assert_eq!(id_generator.split_number_digits(1), [0, 0, 0, 1]);
-        assert_eq!(id_generator.split_number_digits(8), [0, 0, 0, 8]);
-        assert_eq!(id_generator.split_number_digits(10), [0, 0, 1, 0]);
-        assert_eq!(id_generator.split_number_digits(123), [0, 1, 2, 3]);
-        assert_eq!(id_generator.split_number_digits(1234), [1, 2, 3, 4]);
-
-        let id_generator = RandomIdGenerator::new(key.clone(), 0, 3);
-        assert_eq!(id_generator.split_number_digits(0), [0, 0, 0]);
-        assert_eq!(id_generator.split_number_digits(1), [0, 0, 1]);
-        assert_eq!(id_generator.split_number_digits(8), [0, 0, 8]);
-        assert_eq!(id_generator.split_number_digits(10), [0, 1, 0]);
-        assert_eq!(id_generator.split_number_digits(123), [1, 2, 3]);
-
-        let id_generator = RandomIdGenerator::new(key, 0, 2);
-        assert_eq!(id_generator.split_number_digits(0), [0, 0]);
-        assert_eq!(id_generator.split_number_digits(1), [0, 1]);
-        assert_eq!(id_generator.split_number_digits(8), [0, 8]);
-        assert_eq!(id_generator.split_number_digits(10), [1, 0]);
+        assert_eq!(split_digits(0, 4, 10), [0, 0, 0, 0]);
+        assert_eq!(split_digits(1, 4, 10), [0, 0, 0, 1]);
+        assert_eq!(split_digits(8, 4, 10), [0, 0, 0, 8]);
+        assert_eq!(split_digits(10, 4, 10), [0, 0, 1, 0]);
+        assert_eq!(split_digits(123, 4, 10), [0, 1, 2, 3]);
+        assert_eq!(split_digits(1234, 4, 10), [1, 2, 3, 4]);
+
+        assert_eq!(split_digits(0, 3, 10), [0, 0, 0]);
+        assert_eq!(split_digits(1, 3, 10), [0, 0, 1]);
+        assert_eq!(split_digits(8, 3, 10), [0, 0, 8]);
+        assert_eq!(split_digits(10, 3, 10), [0, 1, 0]);
+        assert_eq!(split_digits(123, 3, 10), [1, 2, 3]);
+
+        assert_eq!(split_digits(0, 2, 10), [0, 0]);
+        assert_eq!(split_digits(1, 2, 10), [0, 1]);
+        assert_eq!(split_digits(8, 2, 10), [0, 8]);
+        assert_eq!(split_digits(10, 2, 10), [1, 0]);
     }
 
     #[test]
     fn test_join_number_digits() {
-        let mut rng = rand::thread_rng();
-        let mut key = [0u8; 32];
-        rng.fill(&mut key);
-
-        assert_eq!(RandomIdGenerator::join_number_digits(&[]), 0);
-        assert_eq!(RandomIdGenerator::join_number_digits(&[1]), 1);
-        assert_eq!(RandomIdGenerator::join_number_digits(&[8]), 8);
-        assert_eq!(RandomIdGenerator::join_number_digits(&[1, 0]), 10);
-        assert_eq!(RandomIdGenerator::join_number_digits(&[1, 2, 3]), 123);
-        assert_eq!(RandomIdGenerator::join_number_digits(&[1, 2, 3, 4]), 1234);
-        assert_eq!(RandomIdGenerator::join_number_digits(&[0, 0, 0, 0]), 0);
-        assert_eq!(RandomIdGenerator::join_number_digits(&[0, 0, 0, 1]), 1);
-        assert_eq!(RandomIdGenerator::join_number_digits(&[0, 0, 0, 8]), 8);
-        assert_eq!(RandomIdGenerator::join_number_digits(&[0, 0, 1, 0]), 10);
-        assert_eq!(RandomIdGenerator::join_number_digits(&[0, 1, 2, 3]), 123);
-        assert_eq!(RandomIdGenerator::join_number_digits(&[1, 2, 3, 4]), 1234);
+        assert_eq!(join_digits(&[], 10), 0);
+        assert_eq!(join_digits(&[1], 10), 1);
+        assert_eq!(join_digits(&[8], 10), 8);
+        assert_eq!(join_digits(&[1, 0], 10), 10);
+        assert_eq!(join_digits(&[1, 2, 3], 10), 123);
+        assert_eq!(join_digits(&[1, 2, 3, 4], 10), 1234);
+        assert_eq!(join_digits(&[0, 0, 0, 0], 10), 0);
+        assert_eq!(join_digits(&[0, 0, 0, 1], 10), 1);
+        assert_eq!(join_digits(&[0, 0, 0, 8], 10), 8);
+        assert_eq!(join_digits(&[0, 0, 1, 0], 10), 10);
+        assert_eq!(join_digits(&[0, 1, 2, 3], 10), 123);
+        assert_eq!(join_digits(&[1, 2, 3, 4], 10), 1234);
     }
 
     #[test]
@@ -225,4 +469,181 @@ mod tests {
         assert!(id_generator.nth(0).is_some());
         assert!(id_generator.nth(0).is_none());
     }
+
+    #[test]
+    fn test_wide_digit_space_does_not_overflow() {
+        let mut rng = rand::thread_rng();
+        let mut key = [0u8; 32];
+        rng.fill(&mut key);
+
+        // 10^6 overflows a u16 len (which tops out at 65535), so this would
+        // have silently truncated the iterator before widening to u64.
+        let id_generator = RandomIdGenerator::new(key, 0, 6);
+        assert_eq!(id_generator.size_hint(), (1_000_000, Some(1_000_000)));
+    }
+
+    #[test]
+    fn test_alphabet_id_generator() {
+        let mut rng = rand::thread_rng();
+        let mut key = [0u8; 32];
+        rng.fill(&mut key);
+
+        let alphabet: Vec<char> = ('a'..='z').chain('0'..='9').collect();
+        let mut id_generator = RandomIdGenerator::with_alphabet(key, 0, 4, &alphabet);
+
+        let mut seen = std::collections::HashSet::new();
+        for _ in 0..500 {
+            let id = id_generator.next().unwrap();
+            assert_eq!(id.len(), 4);
+            assert!(id.chars().all(|c| alphabet.contains(&c)));
+            assert!(seen.insert(id));
+        }
+    }
+
+    #[test]
+    fn test_alphabet_id_generator_exhausts_its_space() {
+        let mut rng = rand::thread_rng();
+        let mut key = [0u8; 32];
+        rng.fill(&mut key);
+
+        let alphabet: Vec<char> = ('a'..='z').chain('0'..='9').collect();
+        let mut id_generator = RandomIdGenerator::with_alphabet(key, 0, 4, &alphabet);
+
+        // Jump to just shy of the end instead of iterating all ~1.68M ids.
+        let len = id_generator.len();
+        id_generator.next = len - 5;
+
+        for _ in 0..5 {
+            let id = id_generator.next().unwrap();
+            assert_eq!(id.len(), 4);
+        }
+
+        assert_eq!(id_generator.size_hint(), (0, Some(0)));
+        assert!(id_generator.next().is_none());
+    }
+
+    #[test]
+    fn test_alphabet_id_generator_shares_the_full_feature_set() {
+        let seed = [7u8; 32];
+        let alphabet: Vec<char> = ('a'..='z').chain('0'..='9').collect();
+
+        let mut id_generator = AlphaIdGenerator::from_seed(seed, 0, 4, &alphabet);
+        let first = id_generator.next().unwrap();
+        assert_eq!(id_generator.index_of(&first), Some(0));
+
+        id_generator.resume_from(0);
+        assert_eq!(id_generator.next().unwrap(), first);
+
+        let mut rng = rand::thread_rng();
+        let chosen = id_generator.choose_multiple(&mut rng, 10);
+        assert_eq!(chosen.len(), 10);
+
+        let last = id_generator.next_back().unwrap();
+        assert_ne!(last, first);
+    }
+
+    #[test]
+    fn test_index_of_round_trips() {
+        let mut rng = rand::thread_rng();
+        let mut key = [0u8; 32];
+        rng.fill(&mut key);
+
+        let mut id_generator = RandomIdGenerator::new(key, 0, 6);
+        for index in 0..20u64 {
+            let id = id_generator.next().unwrap();
+            assert_eq!(id_generator.index_of(id), Some(index));
+        }
+    }
+
+    #[test]
+    fn test_index_of_rejects_id_outside_digit_space() {
+        let mut rng = rand::thread_rng();
+        let mut key = [0u8; 32];
+        rng.fill(&mut key);
+
+        let id_generator = RandomIdGenerator::new(key, 0, 6);
+        // 6 digits only cover [0, 1_000_000); a 7-digit id is out of range.
+        assert_eq!(id_generator.index_of(1_000_000), None);
+    }
+
+    #[test]
+    fn test_resume_from_continues_without_repeats() {
+        let mut rng = rand::thread_rng();
+        let mut key = [0u8; 32];
+        rng.fill(&mut key);
+
+        let mut first_run = RandomIdGenerator::new(key, 0, 6);
+        let before: Vec<_> = (&mut first_run).take(10).collect();
+
+        let mut resumed = RandomIdGenerator::new(key, 0, 6);
+        resumed.resume_from(10);
+        let after: Vec<_> = resumed.take(10).collect();
+
+        assert!(before.iter().all(|id| !after.contains(id)));
+    }
+
+    #[test]
+    fn test_from_seed_is_deterministic() {
+        let seed = [42u8; 32];
+
+        let mut a = RandomIdGenerator::from_seed(seed, 0, 6);
+        let mut b = RandomIdGenerator::from_seed(seed, 0, 6);
+
+        assert_eq!(a.next(), b.next());
+    }
+
+    #[test]
+    fn test_from_rng_fills_a_usable_key() {
+        let mut rng = rand::thread_rng();
+
+        let mut id_generator = RandomIdGenerator::from_rng(&mut rng, 0, 6);
+        assert!(id_generator.next().is_some());
+    }
+
+    #[test]
+    fn test_double_ended_iterator_splits_into_disjoint_halves() {
+        let mut rng = rand::thread_rng();
+        let mut key = [0u8; 32];
+        rng.fill(&mut key);
+
+        let mut id_generator = RandomIdGenerator::new(key, 0, 6);
+        assert_eq!(id_generator.size_hint(), (1_000_000, Some(1_000_000)));
+
+        let mut front = Vec::new();
+        let mut back = Vec::new();
+        for i in 0..100 {
+            if i % 2 == 0 {
+                front.push(id_generator.next().unwrap());
+            } else {
+                back.push(id_generator.next_back().unwrap());
+            }
+        }
+
+        assert!(front.iter().all(|id| !back.contains(id)));
+        assert_eq!(
+            id_generator.size_hint(),
+            (1_000_000 - 100, Some(1_000_000 - 100))
+        );
+
+        // Once `next` meets `back`, both ends report the stream as exhausted.
+        id_generator.resume_from(1_000_000);
+        assert!(id_generator.next().is_none());
+        assert!(id_generator.next_back().is_none());
+    }
+
+    #[test]
+    fn test_choose_multiple_is_distinct_and_in_range() {
+        let mut rng = rand::thread_rng();
+        let mut key = [0u8; 32];
+        rng.fill(&mut key);
+
+        let id_generator = RandomIdGenerator::new(key, 0, 6);
+        let ids = id_generator.choose_multiple(&mut rng, 50);
+
+        assert_eq!(ids.len(), 50);
+        assert!(ids.iter().all(|&id| id < 1_000_000));
+
+        let unique: std::collections::HashSet<_> = ids.iter().collect();
+        assert_eq!(unique.len(), 50);
+    }
 }